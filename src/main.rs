@@ -1,9 +1,12 @@
 // Version of the fracture simulation from "Simulating Fractures with Bonded Discrete Element Method" paper
 // Taken from the supplementary material and translated to Rust.
 
+use std::collections::HashMap;
 use std::f64::consts::PI;
+use std::ops::Range;
 
 use macroquad::prelude::*;
+use noise::{NoiseFn, OpenSimplex};
 
 #[derive(Debug, Clone, Copy)]
 struct Bond {
@@ -64,6 +67,116 @@ impl Particle {
     }
 }
 
+// Force field applied to every particle each substep, independent of
+// contacts/bonds. `Uniform` gravity is always on; `Point`/`Vortex`/`Wind`
+// toggle in with the 1/2/3 keys.
+#[derive(Debug, Clone, Copy)]
+enum Effector {
+    // Uniform acceleration, e.g. gravity.
+    Uniform(DVec2),
+    // Point attractor/repulsor, inverse-square falloff; negative strength pulls.
+    Point { position: DVec2, strength: f64 },
+    // Tangential force circling `position`, like a tornado.
+    Vortex { position: DVec2, strength: f64 },
+    // Directional air flow; `as_speed` makes it a damped target velocity
+    // instead of a raw force.
+    Wind {
+        velocity: DVec2,
+        strength: f64,
+        as_speed: bool,
+    },
+    // A damped spring anchored to a single particle by index, toward
+    // `target`; used by the mouse drag tool.
+    Drag {
+        index: usize,
+        target: DVec2,
+        stiffness: f64,
+        damping: f64,
+    },
+}
+impl Effector {
+    // Force (and torque) this effector contributes to particle `i` this
+    // substep; fixed particles (`inverse_mass` ~ 0) are inert to it. Result
+    // is a force, not acceleration. `Uniform` and speed-mode `Wind` are
+    // pre-multiplied by mass so it cancels back out when the caller does
+    // `force * inverse_mass`; the other variants return raw forces.
+    // Takes raw state rather than `&Particle` so RK4 can evaluate it
+    // against an interpolated mid-step position/velocity too.
+    fn evaluate(&self, i: usize, inverse_mass: f64, position: DVec2, velocity: DVec2) -> (DVec2, f64) {
+        if inverse_mass <= 1e-6 {
+            return (DVec2::ZERO, 0.0);
+        }
+        let force = match *self {
+            Effector::Uniform(accel) => accel / inverse_mass,
+            Effector::Point {
+                position: target,
+                strength,
+            } => {
+                let d = target - position;
+                let dist2 = d.length_squared().max(1e-6);
+                d / dist2.sqrt() * (-strength / dist2)
+            }
+            Effector::Vortex {
+                position: axis,
+                strength,
+            } => {
+                let d = position - axis;
+                let dist = d.length().max(1e-6);
+                let tangent = DVec2::new(-d.y, d.x) / dist;
+                tangent * (strength / dist)
+            }
+            Effector::Wind {
+                velocity: wind,
+                strength,
+                as_speed,
+            } => {
+                if as_speed {
+                    (wind - velocity) * strength / inverse_mass
+                } else {
+                    wind * strength
+                }
+            }
+            Effector::Drag {
+                index,
+                target,
+                stiffness,
+                damping,
+            } => {
+                if i != index {
+                    DVec2::ZERO
+                } else {
+                    (target - position) * stiffness - velocity * damping
+                }
+            }
+        };
+        (force, 0.0)
+    }
+}
+
+// State carries momentum, not velocity, per the classic RK4 formulation;
+// Derivative holds its rates (velocity, force, spin, torque).
+#[derive(Debug, Clone, Copy, Default)]
+struct State {
+    position: DVec2,
+    momentum: DVec2,
+    orientation: f64,
+    angular_momentum: f64,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct Derivative {
+    velocity: DVec2,
+    force: DVec2,
+    spin: f64,
+    torque: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Integrator {
+    Verlet,
+    Rk4,
+}
+
 fn range(start: f64, end: f64, step: f64) -> impl Iterator<Item = f64> {
     let mut x = start;
     std::iter::from_fn(move || {
@@ -81,6 +194,329 @@ fn clamp_angle(a: f64) -> f64 {
     (a + PI).rem_euclid(2.0 * PI) - PI
 }
 
+// Largest radius, so the broad-phase grid cell always covers a touching pair.
+fn max_radius(pts: &[Particle]) -> f64 {
+    pts.iter().fold(0.0, |m, p| m.max(p.radius))
+}
+
+fn cell_of(pos: DVec2, cell_size: f64) -> (i32, i32) {
+    (
+        (pos.x / cell_size).floor() as i32,
+        (pos.y / cell_size).floor() as i32,
+    )
+}
+
+// Buckets particle indices into cells of side `cell_size`; built from plain
+// positions so it can also bucket an RK4 sub-evaluation's interpolated state.
+struct Grid {
+    cell_size: f64,
+    cells: HashMap<(i32, i32), Vec<usize>>,
+}
+impl Grid {
+    fn build(positions: impl Iterator<Item = DVec2>, cell_size: f64) -> Self {
+        let mut cells: HashMap<(i32, i32), Vec<usize>> = HashMap::new();
+        for (i, pos) in positions.enumerate() {
+            cells.entry(cell_of(pos, cell_size)).or_default().push(i);
+        }
+        Self { cell_size, cells }
+    }
+
+    // Indices of particles in `pos`'s cell and its 8 neighbors.
+    fn neighbors(&self, pos: DVec2) -> impl Iterator<Item = usize> + '_ {
+        let (cx, cy) = cell_of(pos, self.cell_size);
+        (-1..=1).flat_map(move |dx| {
+            (-1..=1).flat_map(move |dy| {
+                self.cells
+                    .get(&(cx + dx, cy + dy))
+                    .into_iter()
+                    .flatten()
+                    .copied()
+            })
+        })
+    }
+}
+
+// Derivative of particle `i` from an interpolated `states` snapshot.
+// Never mutates `Bond::broken` — an RK4 sub-evaluation must not let a bond
+// half-break partway through a step; see `resolve_bond_breaks`.
+fn evaluate_derivative(
+    pts: &[Particle],
+    states: &[State],
+    grid: &Grid,
+    kn: f64,
+    r2: f64,
+    effectors: &[Effector],
+    i: usize,
+) -> Derivative {
+    let p = &pts[i];
+    let s = &states[i];
+    let velocity = s.momentum * p.inverse_mass;
+    let spin = s.angular_momentum * p.inverse_moment;
+    if p.inverse_mass <= 1e-6 {
+        return Derivative {
+            velocity,
+            force: DVec2::ZERO,
+            spin,
+            torque: 0.0,
+        };
+    }
+    let mut force = DVec2::ZERO;
+    let mut torque = 0.0;
+    for j in grid.neighbors(s.position) {
+        if i == j {
+            continue;
+        }
+        let lij = s.position - states[j].position;
+        let o = p.radius + pts[j].radius - lij.length();
+        if o < 1e-12 {
+            continue;
+        }
+        let n = lij.normalize();
+        let vj = states[j].momentum * pts[j].inverse_mass;
+        let a = 1.4 * (kn / (p.inverse_mass + pts[j].inverse_mass)).sqrt();
+        force += n * (kn * o + a * (vj - velocity).dot(n));
+    }
+    for b in &p.bonds {
+        if b.broken {
+            continue;
+        }
+        let j = b.endpoint as usize;
+        let l = states[j].position - s.position;
+        let n = l.normalize();
+        let t = DVec2::new(-n.y, n.x);
+        let dl = l.length() - b.length;
+        let qb = b.direction.y.atan2(b.direction.x) - n.y.atan2(n.x);
+        let ti = clamp_angle(qb + s.orientation);
+        let tj = clamp_angle(qb + states[j].orientation);
+        let f_n = n * kn * dl;
+        let f_t = t * -kn / 3.0 * r2 / l.length() * (ti + tj);
+        force += f_n + f_t;
+        torque += kn / 6.0 * r2 * (tj - 3.0 * ti);
+    }
+    for effector in effectors {
+        let (f, t) = effector.evaluate(i, p.inverse_mass, s.position, velocity);
+        force += f;
+        torque += t;
+    }
+    Derivative {
+        velocity,
+        force,
+        spin,
+        torque,
+    }
+}
+
+fn advance_states(states0: &[State], d: &[Derivative], h: f64) -> Vec<State> {
+    states0
+        .iter()
+        .zip(d)
+        .map(|(s, d)| State {
+            position: s.position + d.velocity * h,
+            momentum: s.momentum + d.force * h,
+            orientation: clamp_angle(s.orientation + d.spin * h),
+            angular_momentum: s.angular_momentum + d.torque * h,
+        })
+        .collect()
+}
+
+// Classic RK4 (k1..k4, weights (1,2,2,1)/6); call `resolve_bond_breaks`
+// afterward since bond breaking is deliberately not decided here.
+fn rk4_step(pts: &mut [Particle], cell_size: f64, kn: f64, r2: f64, effectors: &[Effector], dt: f64) {
+    let n = pts.len();
+    let states0: Vec<State> = pts
+        .iter()
+        .map(|p| State {
+            position: p.position,
+            momentum: if p.inverse_mass > 1e-6 {
+                p.velocity / p.inverse_mass
+            } else {
+                DVec2::ZERO
+            },
+            orientation: p.angle,
+            angular_momentum: if p.inverse_moment > 1e-6 {
+                p.angvel / p.inverse_moment
+            } else {
+                0.0
+            },
+        })
+        .collect();
+
+    let eval_all = |states: &[State]| -> Vec<Derivative> {
+        let grid = Grid::build(states.iter().map(|s| s.position), cell_size);
+        (0..n)
+            .map(|i| evaluate_derivative(pts, states, &grid, kn, r2, effectors, i))
+            .collect()
+    };
+
+    let k1 = eval_all(&states0);
+    let k2 = eval_all(&advance_states(&states0, &k1, 0.5 * dt));
+    let k3 = eval_all(&advance_states(&states0, &k2, 0.5 * dt));
+    let k4 = eval_all(&advance_states(&states0, &k3, dt));
+
+    for i in 0..n {
+        let avg = Derivative {
+            velocity: (k1[i].velocity + 2.0 * k2[i].velocity + 2.0 * k3[i].velocity + k4[i].velocity) / 6.0,
+            force: (k1[i].force + 2.0 * k2[i].force + 2.0 * k3[i].force + k4[i].force) / 6.0,
+            spin: (k1[i].spin + 2.0 * k2[i].spin + 2.0 * k3[i].spin + k4[i].spin) / 6.0,
+            torque: (k1[i].torque + 2.0 * k2[i].torque + 2.0 * k3[i].torque + k4[i].torque) / 6.0,
+        };
+        let s0 = states0[i];
+        let p = &mut pts[i];
+        p.position = s0.position + avg.velocity * dt;
+        p.angle = clamp_angle(s0.orientation + avg.spin * dt);
+        p.velocity = (s0.momentum + avg.force * dt) * p.inverse_mass;
+        p.velocity_mid = p.velocity;
+        p.angvel = (s0.angular_momentum + avg.torque * dt) * p.inverse_moment;
+        p.angvel_mid = p.angvel;
+    }
+}
+
+// Breaking must only happen once per step, never inside an RK4
+// sub-evaluation, or a bond could be intact for k1 but broken for k2.
+fn resolve_bond_breaks(pts: &mut [Particle], kn: f64, r2: f64, r: f64) {
+    for i in 0..pts.len() {
+        for iter in 0..pts[i].bonds.len() {
+            let b = pts[i].bonds[iter];
+            if b.broken {
+                continue;
+            }
+            let j = b.endpoint as usize;
+            let l = pts[j].position - pts[i].position;
+            let n = l.normalize();
+            let dl = l.length() - b.length;
+            let qb = b.direction.y.atan2(b.direction.x) - n.y.atan2(n.x);
+            let ti = clamp_angle(qb + pts[i].angle);
+            let tj = clamp_angle(qb + pts[j].angle);
+            let f_n = n * kn * dl;
+            let f_t_len = (-kn / 3.0 * r2 / l.length() * (ti + tj)).abs();
+            if (dl > 0.0 && (f_n.length() / 2.0 / r + (kn / 2.0 * (tj - ti)).abs()) > b.max_normal_force)
+                || f_t_len / 2.0 / r > b.max_tangent_force
+            {
+                pts[i].bonds[iter].broken = true;
+            }
+        }
+    }
+}
+
+/// One octave of radial noise layered onto a contour.
+#[derive(Debug, Clone, Copy)]
+struct NoiseOctave {
+    frequency: f64,
+    amplitude: f64,
+}
+
+/// How particles are packed inside a body's outline.
+#[derive(Debug, Clone, Copy)]
+enum Lattice {
+    Square,
+    Hex,
+}
+
+// Candidate centers tiling a square of side `2*extent`, spaced `2*r` apart.
+fn lattice_points(lattice: Lattice, extent: f64, r: f64) -> Vec<DVec2> {
+    let mut points = vec![];
+    match lattice {
+        Lattice::Square => {
+            for x in range(-extent, extent, 2.0 * r) {
+                for y in range(-extent, extent, 2.0 * r) {
+                    points.push(DVec2::new(x, y));
+                }
+            }
+        }
+        Lattice::Hex => {
+            let row_height = r * 3.0_f64.sqrt();
+            for (row, y) in range(-extent, extent, row_height).enumerate() {
+                let x_offset = if row % 2 == 0 { 0.0 } else { r };
+                for x in range(-extent + x_offset, extent, 2.0 * r) {
+                    points.push(DVec2::new(x, y));
+                }
+            }
+        }
+    }
+    points
+}
+
+// Radius sampled on a circle of radius `octave.frequency`, so the contour
+// wraps around seamlessly at theta = 0/2*pi.
+fn contour_radius(noise: &OpenSimplex, theta: f64, base_radius: f64, octaves: &[NoiseOctave]) -> f64 {
+    octaves.iter().fold(base_radius, |r, o| {
+        r + o.amplitude * noise.get([theta.cos() * o.frequency, theta.sin() * o.frequency])
+    })
+}
+
+// Bonds every pair of particles in `range` that overlaps or is within
+// 0.1*r of touching, with rest length 2*r.
+fn bond_overlapping(pts: &mut [Particle], range: Range<usize>, kn: f64, r: f64) {
+    for i in range.clone() {
+        for j in range.clone() {
+            if i == j {
+                continue;
+            }
+            let l = pts[j].position - pts[i].position;
+            let overlap = pts[i].radius + pts[j].radius - l.length();
+            if overlap >= -0.1 * r {
+                pts[i].bonds.push(Bond::new(
+                    j as u32,
+                    2.0 * r,
+                    l.normalize(),
+                    0.07 * kn,
+                    0.07 * kn,
+                ));
+            }
+        }
+    }
+}
+
+// An irregular, rock-like body: particles packed on `lattice` inside a noisy
+// radial contour, bonded to every neighbor within `2*r`.
+#[allow(clippy::too_many_arguments)]
+fn spawn_noisy_body(
+    pts: &mut Vec<Particle>,
+    center: DVec2,
+    base_radius: f64,
+    octaves: &[NoiseOctave],
+    seed: u32,
+    lattice: Lattice,
+    r: f64,
+    invmass: f64,
+    invmoment: f64,
+    kn: f64,
+    color: Color,
+) {
+    let noise = OpenSimplex::new(seed);
+    let extent = base_radius + octaves.iter().map(|o| o.amplitude).sum::<f64>();
+    let start = pts.len();
+    for offset in lattice_points(lattice, extent, r) {
+        let theta = offset.y.atan2(offset.x);
+        if offset.length() <= contour_radius(&noise, theta, base_radius, octaves) {
+            pts.push(Particle::new(invmass, invmoment, r, center + offset, color));
+        }
+    }
+    let end = pts.len();
+    bond_overlapping(pts, start..end, kn, r);
+}
+
+// Inverse of the render loop's screen transform.
+fn screen_to_world(screen_x: f32, screen_y: f32, scaling: f32) -> DVec2 {
+    DVec2::new(
+        ((screen_x - screen_height() / 2.0) / scaling) as f64,
+        ((screen_width() / 2.0 - screen_y) / scaling) as f64,
+    ) + DVec2::splat(0.5)
+}
+
+// Index of the non-fixed particle nearest `world`.
+fn nearest_movable(pts: &[Particle], world: DVec2) -> Option<usize> {
+    pts.iter()
+        .enumerate()
+        .filter(|(_, p)| p.inverse_mass > 1e-6)
+        .min_by(|(_, a), (_, b)| {
+            a.position
+                .distance_squared(world)
+                .total_cmp(&b.position.distance_squared(world))
+        })
+        .map(|(i, _)| i)
+}
+
 #[macroquad::main("Fracture 2d")]
 async fn main() {
     let fps: f64 = 60.0;
@@ -114,24 +550,8 @@ async fn main() {
             ));
         }
     }
-    for i in 0..pts.len() {
-        for j in 0..pts.len() {
-            if i == j {
-                continue;
-            }
-            let l = pts[j].position - pts[i].position;
-            let overlap = pts[i].radius + pts[j].radius - l.length();
-            if overlap >= -0.1 * r {
-                pts[i].bonds.push(Bond::new(
-                    j as u32,
-                    2.0 * r,
-                    l.normalize(),
-                    0.07 * kn,
-                    0.07 * kn,
-                ));
-            }
-        }
-    }
+    let rect_end = pts.len();
+    bond_overlapping(&mut pts, 0..rect_end, kn, r);
     for x in range(r, 1.0, 2.0 * r) {
         pts.push(Particle::new(0.0, 0.0, r, DVec2::new(x, 0.0), GRAY));
         pts.push(Particle::new(0.0, 0.0, r, DVec2::new(x, 1.0), GRAY));
@@ -149,12 +569,147 @@ async fn main() {
             ));
         }
     }
+    // A procedurally-generated rock, dropped above the slab so it smashes
+    // against it under gravity.
+    spawn_noisy_body(
+        &mut pts,
+        DVec2::new(0.5, 0.85),
+        0.08,
+        &[
+            NoiseOctave {
+                frequency: 2.0,
+                amplitude: 0.03,
+            },
+            NoiseOctave {
+                frequency: 5.0,
+                amplitude: 0.012,
+            },
+        ],
+        1,
+        Lattice::Hex,
+        r,
+        particle_invmass,
+        particle_invmoment,
+        kn,
+        RED,
+    );
+    // A second, smaller pebble on the square lattice, dropped alongside the
+    // hex-packed rock so the two packings can be compared.
+    spawn_noisy_body(
+        &mut pts,
+        DVec2::new(0.2, 0.9),
+        0.05,
+        &[NoiseOctave {
+            frequency: 3.0,
+            amplitude: 0.02,
+        }],
+        2,
+        Lattice::Square,
+        r,
+        particle_invmass,
+        particle_invmoment,
+        kn,
+        GREEN,
+    );
     // Extra timestep?
     let s = (1.0 / fps / (7.5e3 * r2 / kn)) as u32 * 10;
     let dt = 1.0 / fps / s as f64;
     println!("S: {:?}", s);
+    // Broad-phase cell size: the interaction cutoff is the sum of the two
+    // largest radii that could touch, so 2*r_max is always wide enough to
+    // catch any overlapping pair in the same or a neighboring cell.
+    let cell_size = 2.0 * max_radius(&pts);
+    let base_effectors = vec![Effector::Uniform(DVec2::new(0.0, -9.8))];
+    // Tab toggles this between Verlet and Rk4, to compare stability/energy
+    // drift against each other at this dt.
+    let mut integrator = Integrator::Verlet;
+    let scaling: f32 = 500.0;
+    // Where a fired projectile leaves from.
+    let muzzle = DVec2::new(0.05, 0.5);
+    // The particle currently being spring-dragged by the mouse, if any.
+    let mut dragged: Option<usize> = None;
+    // Key 1/2/3 adds a Point/Vortex/Wind effector on top of gravity, 0 clears it.
+    let mut extra_effector: Option<Effector> = None;
     loop {
+        let world = screen_to_world(mouse_position().0, mouse_position().1, scaling);
+
+        if is_key_pressed(KeyCode::Tab) {
+            integrator = match integrator {
+                Integrator::Verlet => Integrator::Rk4,
+                Integrator::Rk4 => Integrator::Verlet,
+            };
+        }
+        if is_key_pressed(KeyCode::Key1) {
+            extra_effector = Some(Effector::Point {
+                position: muzzle,
+                strength: 0.05,
+            });
+        }
+        if is_key_pressed(KeyCode::Key2) {
+            extra_effector = Some(Effector::Vortex {
+                position: DVec2::new(0.5, 0.5),
+                strength: 0.05,
+            });
+        }
+        if is_key_pressed(KeyCode::Key3) {
+            extra_effector = Some(Effector::Wind {
+                velocity: DVec2::new(1.0, 0.0),
+                strength: 0.3,
+                as_speed: true,
+            });
+        }
+        if is_key_pressed(KeyCode::Key0) {
+            extra_effector = None;
+        }
+
+        // Right button: press to grab the nearest particle, hold to keep
+        // spring-dragging it toward the cursor, release to let go.
+        if is_mouse_button_pressed(MouseButton::Right) {
+            dragged = nearest_movable(&pts, world);
+        }
+        if is_mouse_button_released(MouseButton::Right) {
+            dragged = None;
+        }
+
+        // Left button: a plain click gives the nearest particle a
+        // one-shot impulse pushed toward the cursor; holding Ctrl instead
+        // fires a fast, heavy projectile from the muzzle toward the
+        // cursor, to test impact-induced cracking.
+        if is_mouse_button_pressed(MouseButton::Left) {
+            if is_key_down(KeyCode::LeftControl) {
+                let dir = (world - muzzle).normalize_or_zero();
+                let mut projectile =
+                    Particle::new(particle_invmass * 0.05, particle_invmoment, r, muzzle, RED);
+                projectile.velocity = dir * 6.0;
+                projectile.velocity_mid = projectile.velocity;
+                pts.push(projectile);
+            } else if let Some(i) = nearest_movable(&pts, world) {
+                let dir = (world - pts[i].position).normalize_or_zero();
+                let impulse = dir * 2.0;
+                pts[i].velocity += impulse;
+                pts[i].velocity_mid += impulse;
+            }
+        }
+
+        let mut effectors = base_effectors.clone();
+        if let Some(index) = dragged {
+            effectors.push(Effector::Drag {
+                index,
+                target: world,
+                stiffness: 200.0,
+                damping: 5.0,
+            });
+        }
+        if let Some(e) = extra_effector {
+            effectors.push(e);
+        }
+
         for _ in 0..1000 {
+            if integrator == Integrator::Rk4 {
+                rk4_step(&mut pts, cell_size, kn, r2, &effectors, dt);
+                resolve_bond_breaks(&mut pts, kn, r2, r);
+                continue;
+            }
             for particle in &mut pts {
                 particle.force = DVec2::ZERO;
                 particle.torque = 0.0;
@@ -162,11 +717,12 @@ async fn main() {
                 particle.angle += particle.angvel_mid * dt;
                 particle.angle = clamp_angle(particle.angle);
             }
+            let grid = Grid::build(pts.iter().map(|p| p.position), cell_size);
             for i in 0..pts.len() {
                 if pts[i].inverse_mass <= 1e-6 {
                     continue;
                 }
-                for j in 0..pts.len() {
+                for j in grid.neighbors(pts[i].position) {
                     if i == j {
                         continue;
                     }
@@ -206,26 +762,25 @@ async fn main() {
                     pts[i].force += f_n + f_t;
                     pts[i].torque += t;
                 }
-                for particle in &mut pts {
-                    let acc = particle.force * particle.inverse_mass
-                        + if particle.inverse_mass > 1e-6 {
-                            DVec2::new(0.0, -9.8)
-                        } else {
-                            DVec2::ZERO
-                        };
-                    particle.velocity = particle.velocity_mid + acc * 0.5 * dt;
-                    particle.velocity_mid += acc * dt;
-                    particle.angvel =
-                        particle.angvel_mid + particle.torque * particle.inverse_moment * 0.5 * dt;
-                    particle.angvel_mid += particle.torque * particle.inverse_moment * dt;
+                for effector in &effectors {
+                    let (f, t) =
+                        effector.evaluate(i, pts[i].inverse_mass, pts[i].position, pts[i].velocity);
+                    pts[i].force += f;
+                    pts[i].torque += t;
                 }
             }
+            for particle in &mut pts {
+                let acc = particle.force * particle.inverse_mass;
+                particle.velocity = particle.velocity_mid + acc * 0.5 * dt;
+                particle.velocity_mid += acc * dt;
+                particle.angvel =
+                    particle.angvel_mid + particle.torque * particle.inverse_moment * 0.5 * dt;
+                particle.angvel_mid += particle.torque * particle.inverse_moment * dt;
+            }
         }
 
         // Rendering
 
-        let scaling = 500.0;
-
         clear_background(WHITE);
         for p in &pts {
             let a = p.position - DVec2::splat(0.5);